@@ -1,17 +1,23 @@
 // Build and print a deck.
 
-use cards::Deck;
+use cards::{Deck, Glyphs};
 
 fn main() {
     let mut deck = Deck::full();
+    let mut glyph = false;
 
     match std::env::args().nth(1).as_deref() {
         Some("bare") => (),
+        Some("glyph") => glyph = true,
         None => deck.shuffle(),
         _ => panic!("unknown argument"),
     }
 
-    for card in deck.iter() {
-        println!("{}", card);
+    if glyph {
+        println!("{}", Glyphs(&deck));
+    } else {
+        for card in deck.iter() {
+            println!("{}", card);
+        }
     }
 }
@@ -10,10 +10,13 @@
 // https://github.com/r-darwish/war/tree/
 //   a43e4723898ae5f48fe1608f9622168a8aa2ca41
 
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng, SeedableRng};
+use std::collections::HashMap;
 use std::fmt;
 use std::iter::Iterator;
+use std::str::FromStr;
 use std::vec::Vec;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
@@ -35,6 +38,46 @@ impl fmt::Display for Suit {
     }
 }
 
+impl FromStr for Suit {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let c = chars.next().ok_or(ParseCardError::BadLength)?;
+        if chars.next().is_some() {
+            return Err(ParseCardError::BadLength);
+        }
+        SUIT_NAMES
+            .iter()
+            .position(|&n| n == c)
+            .map(|i| SUITS[i])
+            .ok_or(ParseCardError::UnknownSuit)
+    }
+}
+
+impl Suit {
+    /// Unicode suit symbol for this suit: ♣ ♦ ♥ ♠.
+    pub fn symbol(self) -> char {
+        match self {
+            Clubs => '♣',
+            Diamonds => '♦',
+            Hearts => '♥',
+            Spades => '♠',
+        }
+    }
+
+    /// Code point one below this suit's Ace in the Unicode
+    /// playing-card block (U+1F0A0), e.g. U+1F0A0 for Spades
+    /// whose Ace is U+1F0A1.
+    fn glyph_base(self) -> u32 {
+        match self {
+            Spades => 0x1F0A0,
+            Hearts => 0x1F0B0,
+            Diamonds => 0x1F0C0,
+            Clubs => 0x1F0D0,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub enum Rank {
     Two,
@@ -72,6 +115,95 @@ impl fmt::Display for Rank {
     }
 }
 
+impl FromStr for Rank {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let c = chars.next().ok_or(ParseCardError::BadLength)?;
+        if chars.next().is_some() {
+            return Err(ParseCardError::BadLength);
+        }
+        if let Some(d) = c.to_digit(10) {
+            return if (2..=9).contains(&d) {
+                Ok(RANKS[d as usize - 2])
+            } else {
+                Err(ParseCardError::UnknownRank)
+            };
+        }
+        let names_start = RANKS.len() - RANK_NAMES.len();
+        RANK_NAMES
+            .iter()
+            .position(|&n| n == c)
+            .map(|i| RANKS[names_start + i])
+            .ok_or(ParseCardError::UnknownRank)
+    }
+}
+
+impl Rank {
+    /// Offset of this rank from its suit's `glyph_base` in the
+    /// Unicode playing-card block (Ace is 1), skipping the unused Knight slot
+    /// (U+1F0AC) so Jack, Queen and King land correctly.
+    /// Not meaningful for `Joker`, which has its own glyphs.
+    fn glyph_offset(self) -> u32 {
+        match self {
+            Ace => 0x1,
+            Two => 0x2,
+            Three => 0x3,
+            Four => 0x4,
+            Five => 0x5,
+            Six => 0x6,
+            Seven => 0x7,
+            Eight => 0x8,
+            Nine => 0x9,
+            Ten => 0xA,
+            Jack => 0xB,
+            Queen => 0xD,
+            King => 0xE,
+            Joker => unreachable!("joker has no suit glyph offset"),
+        }
+    }
+
+    /// Numeric value of this rank for sequencing: Two is 2
+    /// through Ace is 14. `Joker` has no natural place in the
+    /// sequence and is given the value 0.
+    pub fn value(self) -> u8 {
+        match self {
+            Joker => 0,
+            Ace => 14,
+            _ => 2 + self as u8,
+        }
+    }
+
+    /// Is this a face card: Jack, Queen, or King?
+    pub fn is_face(self) -> bool {
+        matches!(self, Jack | Queen | King)
+    }
+
+    /// Is this an Ace?
+    pub fn is_ace(self) -> bool {
+        matches!(self, Ace)
+    }
+
+    /// Is this a King?
+    pub fn is_king(self) -> bool {
+        matches!(self, King)
+    }
+
+    /// Does `other` directly follow this rank in sequence,
+    /// i.e. is `other`'s value exactly one more than this
+    /// rank's? If `ace_low` is set, Ace is also treated as
+    /// directly preceding Two, for games that rank it low.
+    pub fn is_followed_by(self, other: Rank, ace_low: bool) -> bool {
+        if self == Joker || other == Joker {
+            return false;
+        }
+        if ace_low && self == Ace && other == Two {
+            return true;
+        }
+        other.value() == self.value() + 1
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub enum Color {
     Black,
@@ -89,6 +221,57 @@ impl fmt::Display for Color {
     }
 }
 
+impl FromStr for Color {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let c = chars.next().ok_or(ParseCardError::BadLength)?;
+        if chars.next().is_some() {
+            return Err(ParseCardError::BadLength);
+        }
+        COLOR_NAMES
+            .iter()
+            .position(|&n| n == c)
+            .map(|i| COLORS[i])
+            .ok_or(ParseCardError::UnknownSuit)
+    }
+}
+
+/// The suit relationship required between consecutive cards
+/// of a run, as checked by `Card::forms_run_with`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RunKind {
+    /// Cards must share a suit, e.g. a straight flush.
+    SameSuit,
+    /// Cards must have alternating colors, e.g. a Klondike
+    /// tableau sequence.
+    AlternatingColor,
+}
+
+/// An error encountered while parsing a `Suit`, `Rank`,
+/// `Color`, or `Card` from a string.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseCardError {
+    /// The suit (or color) character was not recognized.
+    UnknownSuit,
+    /// The rank character was not recognized.
+    UnknownRank,
+    /// The token was not the expected number of characters.
+    BadLength,
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseCardError::UnknownSuit => write!(f, "unknown suit"),
+            ParseCardError::UnknownRank => write!(f, "unknown rank"),
+            ParseCardError::BadLength => write!(f, "wrong number of characters"),
+        }
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
 /// A playing card.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub enum Card {
@@ -108,6 +291,25 @@ impl fmt::Display for Card {
     }
 }
 
+impl FromStr for Card {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let first = chars.next().ok_or(ParseCardError::BadLength)?;
+        let second = chars.next().ok_or(ParseCardError::BadLength)?;
+        if chars.next().is_some() {
+            return Err(ParseCardError::BadLength);
+        }
+        if second == '?' {
+            let color = first.to_string().parse()?;
+            return Ok(JokerCard(color));
+        }
+        let rank = first.to_string().parse()?;
+        let suit = second.to_string().parse()?;
+        Ok(SuitCard(suit, rank))
+    }
+}
+
 impl From<Suit> for Color {
     fn from(suit: Suit) -> Self {
         match suit {
@@ -205,6 +407,229 @@ impl Card {
     pub fn iter_full() -> IterCards {
         IterCards::full()
     }
+
+    /// Render this card as a Unicode playing-card glyph from
+    /// the U+1F0A0 block, e.g. the Ace of Spades is 🂡.
+    pub fn to_glyph(self) -> char {
+        let code = match self {
+            SuitCard(suit, rank) => suit.glyph_base() + rank.glyph_offset(),
+            JokerCard(Black) => 0x1F0CF,
+            JokerCard(Red) => 0x1F0BF,
+        };
+        char::from_u32(code).expect("valid playing-card code point")
+    }
+
+    /// Does `next` directly follow this card in a run of the
+    /// given `kind`? Combines `Rank::is_followed_by` with a
+    /// suit/color check; jokers never form a run.
+    pub fn forms_run_with(self, next: Card, kind: RunKind, ace_low: bool) -> bool {
+        match (self.suit(), next.suit()) {
+            (Some(suit), Some(next_suit)) => {
+                let suits_match = match kind {
+                    RunKind::SameSuit => suit == next_suit,
+                    RunKind::AlternatingColor => self.color() != next.color(),
+                };
+                suits_match && self.rank().is_followed_by(next.rank(), ace_low)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Total number of distinct cards in the 54-card
+/// universe (13 ranks × 4 suits, plus 2 jokers).
+const CARD_COUNT: u32 = 54;
+
+/// Stable index 0..54 for a card, in the same
+/// rank-major/suit-minor order that `IterCards::full()`
+/// produces, with the two jokers last.
+fn card_index(card: Card) -> u32 {
+    match card {
+        SuitCard(suit, rank) => rank as u32 * SUITS.len() as u32 + suit as u32,
+        JokerCard(color) => SUITS.len() as u32 * (RANKS.len() as u32 - 1) + color as u32,
+    }
+}
+
+/// Inverse of `card_index`.
+fn index_card(index: u32) -> Card {
+    let standard_count = SUITS.len() as u32 * (RANKS.len() as u32 - 1);
+    if index < standard_count {
+        let rank = RANKS[(index / SUITS.len() as u32) as usize];
+        let suit = SUITS[(index % SUITS.len() as u32) as usize];
+        SuitCard(suit, rank)
+    } else {
+        JokerCard(COLORS[(index - standard_count) as usize])
+    }
+}
+
+/// A bit-packed set of cards drawn from the 54-card
+/// universe. Each card occupies one bit of a `u64`, so
+/// membership, union, intersection and difference are all
+/// O(1) instead of scanning a `Vec<Card>`.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug, Hash)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    /// The empty set.
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    /// Add `card` to this set.
+    pub fn insert(&mut self, card: Card) {
+        self.0 |= 1 << card_index(card);
+    }
+
+    /// Remove `card` from this set.
+    pub fn remove(&mut self, card: Card) {
+        self.0 &= !(1 << card_index(card));
+    }
+
+    /// Is `card` a member of this set?
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & (1 << card_index(card)) != 0
+    }
+
+    /// Number of cards in this set.
+    pub fn len(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Is this set empty?
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Union of this set with `other`.
+    pub fn union(self, other: Self) -> Self {
+        self | other
+    }
+
+    /// Intersection of this set with `other`.
+    pub fn intersection(self, other: Self) -> Self {
+        self & other
+    }
+
+    /// Cards in this set but not in `other`.
+    pub fn difference(self, other: Self) -> Self {
+        self & !other
+    }
+
+    /// Cards in the 54-card universe that are not in this set.
+    pub fn complement(self) -> Self {
+        !self
+    }
+
+    /// Iterate over the cards in this set, in index order.
+    pub fn iter(&self) -> CardSetIter {
+        CardSetIter { bits: self.0 }
+    }
+}
+
+impl std::ops::BitOr for CardSet {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitAnd for CardSet {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl std::ops::Not for CardSet {
+    type Output = Self;
+    fn not(self) -> Self {
+        Self(!self.0 & ((1 << CARD_COUNT) - 1))
+    }
+}
+
+/// Iterator over the cards of a `CardSet`, walking set bits
+/// low to high.
+pub struct CardSetIter {
+    bits: u64,
+}
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        Some(index_card(index))
+    }
+}
+
+impl<'a> From<&'a Deck> for CardSet {
+    fn from(deck: &'a Deck) -> Self {
+        let mut set = CardSet::new();
+        for &card in deck.iter() {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+impl From<CardSet> for Deck {
+    fn from(set: CardSet) -> Self {
+        Deck::from_inner(set.iter().collect())
+    }
+}
+
+/// Tracks how many copies of each card remain available, for
+/// multi-deck games and discard-pile bookkeeping.
+#[derive(Debug, Clone, Default)]
+pub struct CardCounts {
+    counts: HashMap<Card, u32>,
+}
+
+impl CardCounts {
+    /// Make an empty count map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts for `num_decks` standard decks (no jokers).
+    pub fn standard(num_decks: usize) -> Self {
+        Self::from_cards(Card::iter_standard(), num_decks)
+    }
+
+    /// Counts for `num_decks` full decks (with jokers).
+    pub fn full(num_decks: usize) -> Self {
+        Self::from_cards(Card::iter_full(), num_decks)
+    }
+
+    fn from_cards(cards: IterCards, num_decks: usize) -> Self {
+        let counts = cards.map(|card| (card, num_decks as u32)).collect();
+        Self { counts }
+    }
+
+    /// How many copies of `card` remain?
+    pub fn get(&self, card: Card) -> u32 {
+        self.counts.get(&card).copied().unwrap_or(0)
+    }
+
+    /// Add one copy of `card`.
+    pub fn increment(&mut self, card: Card) {
+        *self.counts.entry(card).or_insert(0) += 1;
+    }
+
+    /// Remove one copy of `card`, saturating at zero.
+    pub fn decrement(&mut self, card: Card) {
+        if let Some(count) = self.counts.get_mut(&card) {
+            *count = count.saturating_sub(1);
+        }
+    }
+
+    /// Total number of cards tracked across all kinds.
+    pub fn total(&self) -> u32 {
+        self.counts.values().sum()
+    }
 }
 
 /// A "deck" of cards is an ordered collection.
@@ -241,6 +666,16 @@ impl Deck {
         }
     }
 
+    /// Make a deck by concatenating `num_decks` full decks,
+    /// for games using more than one standard deck.
+    pub fn multi(num_decks: usize) -> Self {
+        let mut cards = Vec::with_capacity(num_decks * CARD_COUNT as usize);
+        for _ in 0..num_decks {
+            cards.extend(Card::iter_full());
+        }
+        Self { cards }
+    }
+
     /// Iterator over the current deck.
     pub fn iter(&self) -> std::slice::Iter<Card> {
         self.cards.iter()
@@ -249,7 +684,21 @@ impl Deck {
     /// Shuffle the current deck.
     pub fn shuffle(&mut self) {
         let mut rng = thread_rng();
-        self.cards.shuffle(&mut rng)
+        self.shuffle_with(&mut rng)
+    }
+
+    /// Shuffle the current deck using `rng`, for reproducible
+    /// shuffles in tests and simulations.
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng)
+    }
+
+    /// Shuffle the current deck using a `StdRng` seeded from
+    /// `seed`, so the same seed always produces the same
+    /// shuffle.
+    pub fn shuffle_seeded(&mut self, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.shuffle_with(&mut rng)
     }
 
     /// Pop the top card off the current deck.
@@ -257,6 +706,26 @@ impl Deck {
         self.cards.pop()
     }
 
+    /// Pop `n` cards off the current deck into a new `Hand`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `n` cards remain. Use `try_deal`
+    /// to handle this case without panicking.
+    pub fn deal(&mut self, n: usize) -> Hand {
+        self.try_deal(n).expect("not enough cards left to deal")
+    }
+
+    /// Pop `n` cards off the current deck into a new `Hand`,
+    /// or `None` if fewer than `n` cards remain.
+    pub fn try_deal(&mut self, n: usize) -> Option<Hand> {
+        if self.cards.len() < n {
+            return None;
+        }
+        let cards = (0..n).map(|_| self.draw().unwrap()).collect();
+        Some(Hand { cards })
+    }
+
     /// Put `card` on top of the current deck.
     pub fn put(&mut self, card: Card) {
         self.cards.push(card)
@@ -284,6 +753,110 @@ impl Deck {
     }
 }
 
+/// A "hand" of cards dealt from a `Deck`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Hand {
+    cards: Vec<Card>,
+}
+
+impl Hand {
+    /// Make a new empty hand.
+    pub fn new() -> Self {
+        Self { cards: Vec::new() }
+    }
+
+    /// Iterator over the cards in this hand.
+    pub fn iter(&self) -> std::slice::Iter<Card> {
+        self.cards.iter()
+    }
+
+    /// Number of cards in this hand.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Is this hand empty?
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Does this hand contain `card`?
+    pub fn contains(&self, card: Card) -> bool {
+        self.cards.contains(&card)
+    }
+
+    /// Sort the cards in this hand.
+    pub fn sort(&mut self) {
+        self.cards.sort();
+    }
+
+    /// Remove one copy of `card` from this hand, returning
+    /// whether it was present.
+    pub fn remove(&mut self, card: Card) -> bool {
+        match self.cards.iter().position(|&c| c == card) {
+            Some(i) => {
+                self.cards.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the underlying hand as a vector.
+    pub fn into_inner(self) -> Vec<Card> {
+        self.cards
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut cards = self.cards.iter();
+        if let Some(card) = cards.next() {
+            write!(f, "{}", card)?;
+        }
+        for card in cards {
+            write!(f, " {}", card)?;
+        }
+        Ok(())
+    }
+}
+
+/// Wraps a `Deck` so that it `Display`s as a space-joined
+/// string of Unicode playing-card glyphs instead of the
+/// ASCII rank-suit notation.
+pub struct Glyphs<'a>(pub &'a Deck);
+
+impl<'a> fmt::Display for Glyphs<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut cards = self.0.iter();
+        if let Some(card) = cards.next() {
+            write!(f, "{}", card.to_glyph())?;
+        }
+        for card in cards {
+            write!(f, " {}", card.to_glyph())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Deck {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_deck(s)
+    }
+}
+
+/// Parse a deck from whitespace- or comma-separated card
+/// tokens, e.g. the output of the `bare` driver.
+pub fn parse_deck(s: &str) -> Result<Deck, ParseCardError> {
+    let cards = s
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|tok| !tok.is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<Card>, ParseCardError>>()?;
+    Ok(Deck::from_inner(cards))
+}
+
 #[test]
 fn ranks() {
     assert_eq!(
@@ -293,3 +866,177 @@ fn ranks() {
 
     assert!(JokerCard(Black).rank() > SuitCard(Spades, Ace).rank());
 }
+
+#[test]
+fn card_set_ops() {
+    let mut set = CardSet::new();
+    assert!(set.is_empty());
+
+    let ace_spades = SuitCard(Spades, Ace);
+    let red_joker = JokerCard(Red);
+
+    set.insert(ace_spades);
+    set.insert(red_joker);
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(ace_spades));
+    assert!(!set.contains(SuitCard(Clubs, Two)));
+
+    set.remove(ace_spades);
+    assert!(!set.contains(ace_spades));
+    assert!(set.contains(red_joker));
+
+    let full: CardSet = (&Deck::full()).into();
+    assert_eq!(full.len(), 54);
+    assert_eq!(full.complement().len(), 0);
+
+    let standard: CardSet = (&Deck::standard()).into();
+    let jokers = full.difference(standard);
+    assert_eq!(jokers.len(), 2);
+    assert!(jokers.contains(red_joker));
+
+    let deck: Deck = full.into();
+    assert_eq!(deck.into_inner().len(), 54);
+}
+
+#[test]
+fn parse_round_trip() {
+    for card in Card::iter_full() {
+        assert_eq!(card.to_string().parse(), Ok(card));
+    }
+}
+
+#[test]
+fn parse_card_errors() {
+    assert_eq!("AS".parse::<Card>(), Ok(SuitCard(Spades, Ace)));
+    assert_eq!("R?".parse::<Card>(), Ok(JokerCard(Black)));
+    assert_eq!("1S".parse::<Card>(), Err(ParseCardError::UnknownRank));
+    assert_eq!("AX".parse::<Card>(), Err(ParseCardError::UnknownSuit));
+    assert_eq!("ASX".parse::<Card>(), Err(ParseCardError::BadLength));
+}
+
+#[test]
+fn parse_deck_round_trip() {
+    let deck = Deck::full();
+    let text = deck.iter().map(Card::to_string).collect::<Vec<_>>().join(" ");
+    let parsed = parse_deck(&text).unwrap();
+    assert_eq!(parsed.into_inner(), deck.into_inner());
+
+    let comma_separated: Deck = "AS, TH, R?".parse().unwrap();
+    assert_eq!(
+        comma_separated.into_inner(),
+        vec![SuitCard(Spades, Ace), SuitCard(Hearts, Ten), JokerCard(Black)]
+    );
+}
+
+#[test]
+fn glyphs() {
+    assert_eq!(SuitCard(Spades, Ace).to_glyph(), '🂡');
+    assert_eq!(SuitCard(Spades, Ten).to_glyph(), '🂪');
+    assert_eq!(SuitCard(Spades, Jack).to_glyph(), '🂫');
+    assert_eq!(SuitCard(Spades, Queen).to_glyph(), '🂭');
+    assert_eq!(SuitCard(Spades, King).to_glyph(), '🂮');
+    assert_eq!(SuitCard(Hearts, Ace).to_glyph(), '🂱');
+    assert_eq!(JokerCard(Black).to_glyph(), '🃏');
+    assert_eq!(Suit::Spades.symbol(), '♠');
+
+    let deck = Deck::from_inner(vec![SuitCard(Spades, Ace), JokerCard(Black)]);
+    assert_eq!(Glyphs(&deck).to_string(), "🂡 🃏");
+}
+
+#[test]
+fn shuffle_seeded_is_deterministic() {
+    let mut deck1 = Deck::full();
+    deck1.shuffle_seeded(42);
+
+    let mut deck2 = Deck::full();
+    deck2.shuffle_seeded(42);
+
+    let mut deck3 = Deck::full();
+    deck3.shuffle_seeded(43);
+
+    assert_eq!(deck1.into_inner(), deck2.clone().into_inner());
+    assert_ne!(deck2.into_inner(), deck3.into_inner());
+}
+
+#[test]
+fn deal_hand() {
+    let mut deck = Deck::full();
+    let mut hand = deck.deal(5);
+    assert_eq!(hand.len(), 5);
+    assert_eq!(deck.into_inner().len(), 49);
+
+    let card = *hand.iter().next().unwrap();
+    assert!(hand.contains(card));
+    assert!(hand.remove(card));
+    assert!(!hand.contains(card));
+    assert_eq!(hand.len(), 4);
+
+    hand.sort();
+    let sorted = hand.clone().into_inner();
+    let mut expected = sorted.clone();
+    expected.sort();
+    assert_eq!(sorted, expected);
+
+    let mut empty_deck = Deck::new();
+    assert_eq!(empty_deck.try_deal(1), None);
+}
+
+#[test]
+fn rank_sequencing() {
+    assert_eq!(Two.value(), 2);
+    assert_eq!(Ace.value(), 14);
+    assert_eq!(Joker.value(), 0);
+
+    assert!(Jack.is_face());
+    assert!(Queen.is_face());
+    assert!(King.is_face());
+    assert!(!Ace.is_face());
+    assert!(Ace.is_ace());
+    assert!(King.is_king());
+
+    assert!(Two.is_followed_by(Three, false));
+    assert!(!Ace.is_followed_by(Two, false));
+    assert!(Ace.is_followed_by(Two, true));
+    assert!(King.is_followed_by(Ace, false));
+    assert!(!Joker.is_followed_by(Ace, false));
+}
+
+#[test]
+fn card_runs() {
+    let two_clubs = SuitCard(Clubs, Two);
+    let three_clubs = SuitCard(Clubs, Three);
+    let three_diamonds = SuitCard(Diamonds, Three);
+    let two_hearts = SuitCard(Hearts, Two);
+
+    assert!(two_clubs.forms_run_with(three_clubs, RunKind::SameSuit, false));
+    assert!(!two_clubs.forms_run_with(three_diamonds, RunKind::SameSuit, false));
+
+    assert!(two_hearts.forms_run_with(three_clubs, RunKind::AlternatingColor, false));
+    assert!(!two_clubs.forms_run_with(three_clubs, RunKind::AlternatingColor, false));
+
+    assert!(!JokerCard(Black).forms_run_with(two_clubs, RunKind::SameSuit, false));
+}
+
+#[test]
+fn card_counts() {
+    let deck = Deck::multi(2);
+    assert_eq!(deck.into_inner().len(), 108);
+
+    let mut counts = CardCounts::standard(2);
+    let ace_spades = SuitCard(Spades, Ace);
+    assert_eq!(counts.get(ace_spades), 2);
+    assert_eq!(counts.total(), 104);
+    assert_eq!(counts.get(JokerCard(Black)), 0);
+
+    counts.decrement(ace_spades);
+    assert_eq!(counts.get(ace_spades), 1);
+    counts.decrement(ace_spades);
+    counts.decrement(ace_spades);
+    assert_eq!(counts.get(ace_spades), 0);
+
+    counts.increment(JokerCard(Black));
+    assert_eq!(counts.get(JokerCard(Black)), 1);
+
+    let full_counts = CardCounts::full(1);
+    assert_eq!(full_counts.total(), 54);
+}